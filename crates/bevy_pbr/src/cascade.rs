@@ -0,0 +1,99 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+/// Configuration for cascaded shadow maps (CSM) for [`DirectionalLight`]
+/// shadows.
+///
+/// A single orthographic shadow frustum wastes resolution across a large view
+/// depth range. CSM splits the view frustum's depth into [`num_cascades`]
+/// slices and fits a tight ortho frustum around each slice, giving crisp
+/// shadows near the camera without blowing up the shadow map size.
+///
+/// This resource holds the settings and computes the split depths (see
+/// [`cascade_splits`]); the directional light frusta and shadow maps do not
+/// read it, so each directional light uses a single shadow frustum.
+///
+/// [`DirectionalLight`]: crate::DirectionalLight
+/// [`num_cascades`]: CascadeShadowConfig::num_cascades
+/// [`cascade_splits`]: CascadeShadowConfig::cascade_splits
+#[derive(Resource, Debug, Clone, Reflect)]
+pub struct CascadeShadowConfig {
+    /// Number of cascades to split the view frustum depth into.
+    pub num_cascades: usize,
+    /// Blend between a purely uniform (`0.0`) and a purely logarithmic (`1.0`)
+    /// split scheme, following the Zhang et al. convention. Values around `0.5`
+    /// work well for most scenes.
+    pub split_lambda: f32,
+    /// Resolution (in texels, per side) of each cascade's shadow map.
+    pub cascade_resolution: u32,
+}
+
+impl Default for CascadeShadowConfig {
+    fn default() -> Self {
+        CascadeShadowConfig {
+            num_cascades: 4,
+            split_lambda: 0.5,
+            cascade_resolution: 2048,
+        }
+    }
+}
+
+impl CascadeShadowConfig {
+    /// Computes the far depth of each cascade by blending a logarithmic and a
+    /// uniform split of the `[near, far]` range, as described by Zhang et al.
+    ///
+    /// The blend is
+    /// `split_i = lerp(near + (far - near) * (i / N), near * (far / near)^(i / N), lambda)`
+    /// evaluated for `i` in `1..=num_cascades`, so `lambda = 0` is uniform and
+    /// `lambda = 1` is logarithmic.
+    pub fn cascade_splits(&self, near: f32, far: f32) -> Vec<f32> {
+        let n = self.num_cascades as f32;
+        (1..=self.num_cascades)
+            .map(|i| {
+                let fraction = i as f32 / n;
+                let logarithmic = near * (far / near).powf(fraction);
+                let uniform = near + (far - near) * fraction;
+                uniform + self.split_lambda * (logarithmic - uniform)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CascadeShadowConfig;
+
+    #[test]
+    fn lambda_selects_uniform_and_logarithmic_schemes() {
+        let (near, far) = (1.0, 100.0);
+        let uniform = CascadeShadowConfig {
+            split_lambda: 0.0,
+            ..Default::default()
+        };
+        let logarithmic = CascadeShadowConfig {
+            split_lambda: 1.0,
+            ..Default::default()
+        };
+
+        // lambda = 0 reproduces the evenly spaced uniform split.
+        for (i, split) in uniform.cascade_splits(near, far).into_iter().enumerate() {
+            let fraction = (i + 1) as f32 / uniform.num_cascades as f32;
+            assert!((split - (near + (far - near) * fraction)).abs() < 1e-3);
+        }
+
+        // lambda = 1 reproduces the geometric logarithmic split.
+        for (i, split) in logarithmic.cascade_splits(near, far).into_iter().enumerate() {
+            let fraction = (i + 1) as f32 / logarithmic.num_cascades as f32;
+            assert!((split - near * (far / near).powf(fraction)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn splits_are_monotonic_and_end_at_far() {
+        let config = CascadeShadowConfig::default();
+        let splits = config.cascade_splits(0.1, 500.0);
+        assert_eq!(splits.len(), config.num_cascades);
+        assert!(splits.windows(2).all(|w| w[0] < w[1]));
+        assert!((splits.last().copied().unwrap() - 500.0).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,83 @@
+use bevy_asset::Handle;
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_render::texture::Image;
+
+/// Handles for a prefiltered environment map used for image-based lighting
+/// (IBL).
+///
+/// This component only carries the texture handles; the split-sum
+/// approximation that consumes them (diffuse sampling of [`diffuse_map`] by the
+/// surface normal and specular sampling of [`specular_map`] at a
+/// `roughness * max_mip` mip along the reflection vector, scaled by the
+/// [`brdf_lut`] entry at `(NdotV, roughness)`) is not yet wired into
+/// `StandardMaterial` shading.
+///
+/// The three prefiltered textures are expected to be baked ahead of time:
+/// [`diffuse_map`] by cosine-weighted hemisphere convolution and
+/// [`specular_map`] by GGX importance sampling per mip level. No baking pass is
+/// registered by [`PbrPlugin`] yet, so callers must supply already-baked
+/// textures.
+///
+/// [`PbrPlugin`]: crate::PbrPlugin
+/// [`diffuse_map`]: EnvironmentMap::diffuse_map
+/// [`specular_map`]: EnvironmentMap::specular_map
+/// [`brdf_lut`]: EnvironmentMap::brdf_lut
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct EnvironmentMap {
+    /// The source HDR cubemap (e.g. the skybox the maps were baked from).
+    pub source: Handle<Image>,
+    /// Cosine-convolved irradiance cubemap sampled for diffuse IBL.
+    pub diffuse_map: Handle<Image>,
+    /// Roughness-mipped, GGX-prefiltered cubemap sampled for specular IBL.
+    pub specular_map: Handle<Image>,
+    /// 2D lookup table returning the `(scale, bias)` BRDF pair indexed by
+    /// `(NdotV, roughness)`.
+    pub brdf_lut: Handle<Image>,
+    /// Number of mip levels in [`specular_map`]; the top mip corresponds to a
+    /// perfectly smooth surface and the last to fully rough.
+    ///
+    /// [`specular_map`]: EnvironmentMap::specular_map
+    pub specular_mip_levels: u32,
+}
+
+impl EnvironmentMap {
+    /// Selects the specular prefilter mip for a given perceptual `roughness`,
+    /// following the split-sum `roughness * max_mip` convention, where
+    /// `max_mip` is the index of the last mip level.
+    pub fn specular_mip(&self, roughness: f32) -> f32 {
+        roughness * (self.specular_mip_levels.saturating_sub(1)) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnvironmentMap;
+    use bevy_asset::Handle;
+
+    fn env_map(specular_mip_levels: u32) -> EnvironmentMap {
+        EnvironmentMap {
+            source: Handle::default(),
+            diffuse_map: Handle::default(),
+            specular_map: Handle::default(),
+            brdf_lut: Handle::default(),
+            specular_mip_levels,
+        }
+    }
+
+    #[test]
+    fn specular_mip_spans_the_prefilter_chain() {
+        let map = env_map(6);
+        // Smooth surfaces sample the top mip, fully rough surfaces the last.
+        assert_eq!(map.specular_mip(0.0), 0.0);
+        assert_eq!(map.specular_mip(1.0), 5.0);
+        assert_eq!(map.specular_mip(0.5), 2.5);
+    }
+
+    #[test]
+    fn specular_mip_handles_a_single_level() {
+        let map = env_map(1);
+        assert_eq!(map.specular_mip(1.0), 0.0);
+    }
+}
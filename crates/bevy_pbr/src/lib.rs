@@ -2,26 +2,39 @@ pub mod wireframe;
 
 mod alpha;
 mod bundle;
+mod cascade;
+mod environment_map;
 mod light;
 mod material;
 mod pbr_material;
+mod pcss;
 mod render;
+mod spot_light;
+mod ssao;
 
 pub use alpha::*;
 pub use bundle::*;
+pub use cascade::*;
+pub use environment_map::*;
 pub use light::*;
 pub use material::*;
+pub use spot_light::*;
 pub use pbr_material::*;
+pub use pcss::*;
 pub use render::*;
+pub use ssao::*;
 
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         alpha::AlphaMode,
         bundle::{DirectionalLightBundle, MaterialMeshBundle, PbrBundle, PointLightBundle},
+        cascade::CascadeShadowConfig,
+        environment_map::EnvironmentMap,
         light::{AmbientLight, DirectionalLight, PointLight},
         material::{Material, MaterialPlugin},
         pbr_material::StandardMaterial,
+        spot_light::SpotLight,
     };
 }
 
@@ -73,7 +86,10 @@ impl Plugin for PbrPlugin {
             .add_plugin(ExtractComponentPlugin::<Handle<StandardMaterial>>::default())
             .init_resource::<AmbientLight>()
             .init_resource::<DirectionalLightShadowMap>()
+            .init_resource::<CascadeShadowConfig>()
             .init_resource::<PointLightShadowMap>()
+            .init_resource::<SsaoConfig>()
+            .init_resource::<SoftShadowConfig>()
             .init_resource::<AmbientLight>()
             .init_resource::<VisiblePointLights>()
             .add_system_to_stage(
@@ -111,6 +127,17 @@ impl Plugin for PbrPlugin {
                     .after(TransformSystem::TransformPropagate)
                     .after(SimulationLightSystems::AssignLightsToClusters),
             )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                // NOTE: spot lights share the point-light frustum label, so
+                // check_light_mesh_visibility is ordered after them as well.
+                // Spot lights are not assigned to clusters; the ordering after
+                // AssignLightsToClusters mirrors update_point_light_frusta.
+                update_spot_light_frusta
+                    .label(SimulationLightSystems::UpdatePointLightFrusta)
+                    .after(TransformSystem::TransformPropagate)
+                    .after(SimulationLightSystems::AssignLightsToClusters),
+            )
             .add_system_to_stage(
                 CoreStage::PostUpdate,
                 check_light_mesh_visibility
@@ -0,0 +1,77 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+/// Quality settings for percentage-closer soft shadows (PCSS).
+///
+/// PCSS makes penumbra width scale with blocker distance instead of using a
+/// fixed-size PCF kernel: a blocker search over a small region of the shadow
+/// map finds the average depth of occluders nearer than the receiver, the
+/// penumbra size is estimated from that (see [`penumbra`]), and a PCF filter
+/// whose radius matches the penumbra is applied. A single world-space
+/// [`light_size`], shared by all lights, drives how quickly the penumbra grows.
+///
+/// This resource holds the settings and the penumbra estimate only; the shadow
+/// shaders do not read it, so shadow sampling does not use PCSS.
+///
+/// [`penumbra`]: SoftShadowConfig::penumbra
+/// [`light_size`]: SoftShadowConfig::light_size
+#[derive(Resource, Debug, Clone, Reflect)]
+pub struct SoftShadowConfig {
+    /// Number of taps used in the initial blocker search.
+    pub blocker_search_samples: u32,
+    /// Number of taps used in the penumbra-sized PCF filter.
+    pub pcf_samples: u32,
+    /// World-space light size used to drive the penumbra estimate. Larger
+    /// values produce softer, faster growing penumbrae.
+    pub light_size: f32,
+}
+
+impl Default for SoftShadowConfig {
+    fn default() -> Self {
+        SoftShadowConfig {
+            blocker_search_samples: 16,
+            pcf_samples: 16,
+            light_size: 0.1,
+        }
+    }
+}
+
+impl SoftShadowConfig {
+    /// Estimates the penumbra width for a receiver given the average blocker
+    /// depth found during the blocker search, using the similar-triangles
+    /// relation `(receiver - avg_blocker) / avg_blocker * light_size`.
+    ///
+    /// `light_size` is taken from [`SoftShadowConfig::light_size`].
+    ///
+    /// [`SoftShadowConfig::light_size`]: SoftShadowConfig::light_size
+    ///
+    /// Returns `0.0` when no blocker was found (the surface is fully lit).
+    pub fn penumbra(&self, receiver_depth: f32, avg_blocker_depth: f32) -> f32 {
+        if avg_blocker_depth <= 0.0 {
+            return 0.0;
+        }
+        (receiver_depth - avg_blocker_depth) / avg_blocker_depth * self.light_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SoftShadowConfig;
+
+    #[test]
+    fn penumbra_grows_with_blocker_distance() {
+        let config = SoftShadowConfig {
+            light_size: 1.0,
+            ..Default::default()
+        };
+
+        // No blocker found -> fully lit -> zero penumbra.
+        assert_eq!(config.penumbra(10.0, 0.0), 0.0);
+
+        // A blocker closer to the light widens the penumbra.
+        let near_blocker = config.penumbra(10.0, 2.0);
+        let far_blocker = config.penumbra(10.0, 8.0);
+        assert!(near_blocker > far_blocker);
+        assert!((near_blocker - (10.0 - 2.0) / 2.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,85 @@
+use bevy_ecs::prelude::*;
+use bevy_math::Mat4;
+use bevy_reflect::Reflect;
+use bevy_render::{color::Color, primitives::Frustum};
+use bevy_transform::components::GlobalTransform;
+
+/// A light that emits light in a cone, like a flashlight, a stage spotlight, or
+/// a car headlight.
+///
+/// The cone is centred on the `-Z` axis of the light's [`GlobalTransform`] and
+/// opens up to `outer_angle`, which sets the field of view of the frustum built
+/// by [`update_spot_light_frusta`]. That system is the only reader of this
+/// component: spot lights are not extracted to the render world, so no cone
+/// attenuation, shading or shadow map uses them.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct SpotLight {
+    pub color: Color,
+    pub intensity: f32,
+    pub range: f32,
+    pub radius: f32,
+    pub shadows_enabled: bool,
+    pub shadow_depth_bias: f32,
+    pub shadow_normal_bias: f32,
+    /// Angle defining the cone within which the light is at full intensity.
+    pub inner_angle: f32,
+    /// Angle defining the outer edge of the cone, beyond which no light reaches.
+    pub outer_angle: f32,
+}
+
+impl SpotLight {
+    pub const DEFAULT_SHADOW_DEPTH_BIAS: f32 = 0.02;
+    pub const DEFAULT_SHADOW_NORMAL_BIAS: f32 = 0.6;
+}
+
+impl Default for SpotLight {
+    fn default() -> Self {
+        // a quarter turn cone, matching the defaults used for point lights
+        SpotLight {
+            color: Color::rgb(1.0, 1.0, 1.0),
+            intensity: 800.0,
+            range: 20.0,
+            radius: 0.0,
+            shadows_enabled: false,
+            shadow_depth_bias: Self::DEFAULT_SHADOW_DEPTH_BIAS,
+            shadow_normal_bias: Self::DEFAULT_SHADOW_NORMAL_BIAS,
+            inner_angle: 0.0,
+            outer_angle: std::f32::consts::FRAC_PI_4,
+        }
+    }
+}
+
+/// Builds a perspective projection frustum for each [`SpotLight`], mirroring
+/// [`update_point_light_frusta`].
+///
+/// The frustum is recomputed for every spot light each frame; spot lights are
+/// not assigned to clusters, so no cluster-based gating is applied. Light
+/// visibility checks and the shadow pass do not read this frustum.
+///
+/// [`update_point_light_frusta`]: crate::update_point_light_frusta
+pub fn update_spot_light_frusta(
+    mut views: Query<(&GlobalTransform, &SpotLight, &mut Frustum)>,
+) {
+    for (transform, spot_light, mut frustum) in views.iter_mut() {
+        let view_backward = transform.back();
+
+        // the cone is fully contained by a perspective projection with a field of
+        // view equal to twice the outer cone angle
+        let projection = Mat4::perspective_infinite_reverse_rh(
+            2.0 * spot_light.outer_angle,
+            1.0,
+            POINT_LIGHT_NEAR_Z,
+        );
+        let view = transform.compute_matrix().inverse();
+        *frustum = Frustum::from_view_projection(
+            &(projection * view),
+            &transform.translation,
+            &view_backward,
+            spot_light.range,
+        );
+    }
+}
+
+// Matches the near plane used for point light shadow cubemaps.
+const POINT_LIGHT_NEAR_Z: f32 = 0.1;
@@ -0,0 +1,37 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+/// Configuration for screen-space ambient occlusion (SSAO).
+///
+/// SSAO darkens the ambient and IBL diffuse contribution in creases and
+/// contact areas by sampling a hemisphere kernel around each fragment in
+/// view space and comparing the samples' depths against the depth buffer. The
+/// per-pixel kernel is rotated by a small tiled noise texture and the result is
+/// blurred to remove the resulting pattern.
+///
+/// This resource holds the settings only; no SSAO render pass reads it, so the
+/// ambient term is not occluded.
+#[derive(Resource, Debug, Clone, Reflect)]
+pub struct SsaoConfig {
+    /// View-space radius of the sampling hemisphere, in world units.
+    pub radius: f32,
+    /// Depth bias subtracted from the sample comparison to avoid self-occlusion
+    /// acne on flat surfaces.
+    pub bias: f32,
+    /// Scales the final occlusion term; `1.0` leaves it unchanged.
+    pub intensity: f32,
+    /// Number of hemisphere kernel samples taken per fragment. More samples
+    /// reduce noise at the cost of bandwidth.
+    pub sample_count: u32,
+}
+
+impl Default for SsaoConfig {
+    fn default() -> Self {
+        SsaoConfig {
+            radius: 0.5,
+            bias: 0.025,
+            intensity: 1.0,
+            sample_count: 16,
+        }
+    }
+}